@@ -0,0 +1,101 @@
+use specs::prelude::*;
+use specs_derive::Component;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EquipmentSlot {
+    Melee,
+    Shield,
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct Equippable {
+    pub slot: EquipmentSlot,
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct Equipped {
+    pub owner: Entity,
+    pub slot: EquipmentSlot,
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct MeleePowerBonus {
+    pub power: i32,
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct DefenseBonus {
+    pub defense: i32,
+}
+
+/// The masked display name shown for an item whose type hasn't been identified yet.
+#[derive(Component, Debug, Clone)]
+pub struct ObfuscatedName {
+    pub name: String,
+}
+
+#[derive(PartialEq, Copy, Clone, Debug)]
+pub enum HungerState {
+    WellFed,
+    Normal,
+    Hungry,
+    Starving,
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct HungerClock {
+    pub state: HungerState,
+    pub duration: i32,
+}
+
+/// Marker for items (rations) that reset the eater's `HungerClock` to `WellFed`.
+#[derive(Component, Debug, Clone)]
+pub struct ProvidesFood {}
+
+/// `WellFed` grants a small bonus to effective melee power; everything else is neutral.
+pub fn hunger_combat_bonus(hunger_clocks: &ReadStorage<HungerClock>, entity: Entity) -> i32 {
+    match hunger_clocks.get(entity) {
+        Some(clock) if clock.state == HungerState::WellFed => 1,
+        _ => 0,
+    }
+}
+
+/// Marker for scrolls that progressively reveal the whole level when read.
+#[derive(Component, Debug, Clone)]
+pub struct MagicMapper {}
+
+/// Marker for scrolls that recall the player to town, depth 1.
+#[derive(Component, Debug, Clone)]
+pub struct TownPortal {}
+
+/// Remembers where a `TownPortal` user came from, so a portal placed on the town
+/// floor can send them back to the same spot.
+#[derive(Component, Debug, Clone)]
+pub struct ReturnPortal {
+    pub x: i32,
+    pub y: i32,
+    pub depth: i32,
+}
+
+/// Marks the floor entity dropped in town by a `TownPortal` use; stepping onto it
+/// sends whoever holds a `ReturnPortal` back where they came from.
+#[derive(Component, Debug, Clone)]
+pub struct ReturnPortalTile {}
+
+/// Sum of `MeleePowerBonus` across everything the entity currently has `Equipped`.
+pub fn equipped_melee_bonus(equipped: &ReadStorage<Equipped>, power_bonuses: &ReadStorage<MeleePowerBonus>, entity: Entity) -> i32 {
+    (equipped, power_bonuses)
+        .join()
+        .filter(|(equipped, _)| equipped.owner == entity)
+        .map(|(_, bonus)| bonus.power)
+        .sum()
+}
+
+/// Sum of `DefenseBonus` across everything the entity currently has `Equipped`.
+pub fn equipped_defense_bonus(equipped: &ReadStorage<Equipped>, defense_bonuses: &ReadStorage<DefenseBonus>, entity: Entity) -> i32 {
+    (equipped, defense_bonuses)
+        .join()
+        .filter(|(equipped, _)| equipped.owner == entity)
+        .map(|(_, bonus)| bonus.defense)
+        .sum()
+}
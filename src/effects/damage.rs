@@ -0,0 +1,45 @@
+use rltk::RGB;
+use specs::prelude::*;
+
+use crate::particle_system::ParticleBuilder;
+use crate::{CombatStats, Confusion, Position, SufferDamage};
+
+use super::EffectSpawner;
+
+pub fn inflict_damage(ecs: &mut World, _effect: &EffectSpawner, target: Entity, amount: i32) {
+    let mut suffer_damage = ecs.write_storage::<SufferDamage>();
+    SufferDamage::new_damage(&mut suffer_damage, target, amount);
+    drop(suffer_damage);
+
+    particle_at(ecs, target, RGB::named(rltk::RED), RGB::named(rltk::BLACK), rltk::to_cp437('‼'), 200.0);
+}
+
+pub fn heal_damage(ecs: &mut World, _effect: &EffectSpawner, target: Entity, amount: i32) {
+    {
+        let mut combat_stats = ecs.write_storage::<CombatStats>();
+        if let Some(stats) = combat_stats.get_mut(target) {
+            stats.hp = i32::min(stats.max_hp, stats.hp + amount);
+        }
+    }
+
+    particle_at(ecs, target, RGB::named(rltk::GREEN), RGB::named(rltk::BLACK), rltk::to_cp437('♥'), 200.0);
+}
+
+pub fn add_confusion(ecs: &mut World, _effect: &EffectSpawner, target: Entity, turns: i32) {
+    {
+        let mut confused = ecs.write_storage::<Confusion>();
+        confused.insert(target, Confusion { turns }).expect("Unable to insert status");
+    }
+
+    particle_at(ecs, target, RGB::named(rltk::MAGENTA), RGB::named(rltk::BLACK), rltk::to_cp437('?'), 200.0);
+}
+
+fn particle_at(ecs: &mut World, target: Entity, fg: RGB, bg: RGB, glyph: rltk::FontCharType, lifetime_ms: f32) {
+    let positions = ecs.read_storage::<Position>();
+    if let Some(pos) = positions.get(target) {
+        let x = pos.x;
+        let y = pos.y;
+        drop(positions);
+        ecs.fetch_mut::<ParticleBuilder>().request(x, y, fg, bg, glyph, lifetime_ms);
+    }
+}
@@ -0,0 +1,79 @@
+mod damage;
+mod targeting;
+mod teleport;
+mod triggers;
+
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+use specs::prelude::*;
+
+use crate::Map;
+
+pub use targeting::{affect_entity, affect_tile};
+
+lazy_static! {
+    static ref EFFECT_QUEUE: Mutex<VecDeque<EffectSpawner>> = Mutex::new(VecDeque::new());
+}
+
+#[derive(Clone)]
+pub enum EffectType {
+    Damage { amount: i32 },
+    Healing { amount: i32 },
+    Confusion { turns: i32 },
+    ItemUse { item: Entity },
+    EntityDeath,
+    TeleportTo { x: i32, y: i32, depth: i32, player_only: bool, spawn_return: bool },
+}
+
+#[derive(Clone)]
+pub enum Targets {
+    Single { target: Entity },
+    TargetList { targets: Vec<Entity> },
+    Tile { idx: usize },
+    Tiles { tiles: Vec<usize> },
+}
+
+#[derive(Clone)]
+pub struct EffectSpawner {
+    pub creator: Option<Entity>,
+    pub effect_type: EffectType,
+    pub targets: Targets,
+}
+
+pub fn add_effect(creator: Option<Entity>, effect_type: EffectType, targets: Targets) {
+    EFFECT_QUEUE.lock().unwrap().push_back(EffectSpawner { creator, effect_type, targets });
+}
+
+pub fn run_effects_queue(ecs: &mut World) {
+    loop {
+        let effect: Option<EffectSpawner> = EFFECT_QUEUE.lock().unwrap().pop_front();
+        match effect {
+            None => break,
+            Some(effect) => target_applicator(ecs, &effect),
+        }
+    }
+}
+
+fn target_applicator(ecs: &mut World, effect: &EffectSpawner) {
+    match &effect.targets {
+        Targets::Single { target } => affect_entity(ecs, effect, *target),
+        Targets::TargetList { targets } => {
+            for target in targets.iter() {
+                affect_entity(ecs, effect, *target);
+            }
+        }
+        Targets::Tile { idx } => affect_tile(ecs, effect, *idx),
+        Targets::Tiles { tiles } => {
+            for idx in tiles.iter() {
+                affect_tile(ecs, effect, *idx);
+            }
+        }
+    }
+}
+
+pub fn entities_at_tile(ecs: &World, idx: usize) -> Vec<Entity> {
+    let map = ecs.fetch::<Map>();
+    map.tile_content[idx].clone()
+}
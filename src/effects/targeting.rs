@@ -0,0 +1,24 @@
+use specs::prelude::*;
+
+use super::{entities_at_tile, EffectSpawner, EffectType};
+
+pub fn affect_entity(ecs: &mut World, effect: &EffectSpawner, target: Entity) {
+    match &effect.effect_type {
+        EffectType::Damage { amount } => super::damage::inflict_damage(ecs, effect, target, *amount),
+        EffectType::Healing { amount } => super::damage::heal_damage(ecs, effect, target, *amount),
+        EffectType::Confusion { turns } => super::damage::add_confusion(ecs, effect, target, *turns),
+        EffectType::ItemUse { item } => super::triggers::item_trigger(effect.creator, *item, target, ecs),
+        EffectType::EntityDeath => {
+            ecs.entities().delete(target).expect("Unable to delete entity");
+        }
+        EffectType::TeleportTo { x, y, depth, player_only, spawn_return } => {
+            super::teleport::teleport_to(ecs, target, *x, *y, *depth, *player_only, *spawn_return)
+        }
+    }
+}
+
+pub fn affect_tile(ecs: &mut World, effect: &EffectSpawner, tile_idx: usize) {
+    for mob in entities_at_tile(ecs, tile_idx) {
+        affect_entity(ecs, effect, mob);
+    }
+}
@@ -0,0 +1,98 @@
+use rltk::RGB;
+use specs::prelude::*;
+
+use crate::{Map, Name, Position, Renderable, ReturnPortal, ReturnPortalTile, TileType, Viewshed};
+
+/// Moves `target` to `(x, y)` on dungeon level `depth`. If `player_only` is set
+/// and `target` isn't the player, this is a no-op. `spawn_return` controls whether
+/// a `ReturnPortal`/`ReturnPortalTile` pair is left behind for the player to use to
+/// come back — callers should only set this for an outbound `TownPortal` use, not
+/// for the return trip itself, or the player would re-trigger `ReturnPortalSystem`
+/// the moment they step off the landing tile.
+pub fn teleport_to(ecs: &mut World, target: Entity, x: i32, y: i32, depth: i32, player_only: bool, spawn_return: bool) {
+    let player_entity = *ecs.fetch::<Entity>();
+    if player_only && target != player_entity {
+        return;
+    }
+
+    let (x, y) = if target == player_entity {
+        if spawn_return {
+            let current_depth = ecs.fetch::<Map>().depth;
+            let (prev_x, prev_y) = {
+                let positions = ecs.read_storage::<Position>();
+                let pos = positions.get(target).unwrap();
+                (pos.x, pos.y)
+            };
+            ecs.write_storage::<ReturnPortal>()
+                .insert(target, ReturnPortal { x: prev_x, y: prev_y, depth: current_depth })
+                .expect("Unable to insert return portal");
+        }
+
+        let new_map = Map::new_map_rooms_and_corridors(depth);
+        let landing = find_landing_tile(&new_map, x, y);
+        *ecs.write_resource::<Map>() = new_map;
+
+        if spawn_return {
+            spawn_return_portal_tile(ecs, landing.0, landing.1);
+        }
+
+        landing
+    } else {
+        (x, y)
+    };
+
+    ecs.write_storage::<Position>().insert(target, Position { x, y }).expect("Unable to insert position");
+
+    if let Some(viewshed) = ecs.write_storage::<Viewshed>().get_mut(target) {
+        viewshed.dirty = true;
+    }
+}
+
+/// Drops a visible return portal on the floor at `(x, y)`; `return_portal_system`
+/// sends whoever has a `ReturnPortal` component back to it when they step on the tile.
+fn spawn_return_portal_tile(ecs: &mut World, x: i32, y: i32) {
+    ecs.create_entity()
+        .with(Position { x, y })
+        .with(Name { name: "town portal".to_string() })
+        .with(Renderable {
+            glyph: rltk::to_cp437('♥'),
+            fg: RGB::named(rltk::MAGENTA),
+            bg: RGB::named(rltk::BLACK),
+            render_order: 2,
+        })
+        .with(ReturnPortalTile {})
+        .build();
+}
+
+/// Finds the nearest open floor tile to `(x, y)` on `map`, expanding outward ring
+/// by ring. Falls back to `(x, y)` itself if the whole map is solid.
+fn find_landing_tile(map: &Map, x: i32, y: i32) -> (i32, i32) {
+    if is_floor(map, x, y) {
+        return (x, y);
+    }
+
+    let max_radius = map.width.max(map.height);
+    for radius in 1..=max_radius {
+        for dx in -radius..=radius {
+            for dy in -radius..=radius {
+                if dx.abs() != radius && dy.abs() != radius {
+                    continue;
+                }
+                let (tx, ty) = (x + dx, y + dy);
+                if is_floor(map, tx, ty) {
+                    return (tx, ty);
+                }
+            }
+        }
+    }
+
+    (x, y)
+}
+
+fn is_floor(map: &Map, x: i32, y: i32) -> bool {
+    if x < 0 || x >= map.width || y < 0 || y >= map.height {
+        return false;
+    }
+    let idx = map.xy_idx(x, y);
+    map.tiles[idx] == TileType::Floor
+}
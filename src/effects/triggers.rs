@@ -0,0 +1,60 @@
+use specs::prelude::*;
+
+use crate::item_identification::{get_item_display_name, ItemIdentification};
+use crate::{Confusion, Consumable, InflictsDamage, ObfuscatedName, ProvidesHealing};
+
+use super::{add_effect, EffectType, Targets};
+use super::super::{GameLog, Name};
+
+/// Called once per affected target entity when an `EffectType::ItemUse` is applied.
+/// Reads the item's components and enqueues the concrete sub-effects against `target`.
+pub fn item_trigger(creator: Option<Entity>, item: Entity, target: Entity, ecs: &mut World) {
+    let player_entity = *ecs.fetch::<Entity>();
+    let is_player = creator == Some(player_entity);
+    let single = Targets::Single { target };
+
+    if let Some(healer) = ecs.read_storage::<ProvidesHealing>().get(item) {
+        add_effect(creator, EffectType::Healing { amount: healer.heal_amount }, single.clone());
+        if is_player {
+            let item_name = item_display_name(ecs, item);
+            push_log(ecs, format!("You use the {}, healing {} hp.", item_name, healer.heal_amount));
+        }
+    }
+
+    if let Some(damage) = ecs.read_storage::<InflictsDamage>().get(item) {
+        add_effect(creator, EffectType::Damage { amount: damage.damage }, single.clone());
+        if is_player {
+            let item_name = item_display_name(ecs, item);
+            let target_name = name_of(ecs, target);
+            push_log(ecs, format!("You use {} on {}, inflicting {} hp.", item_name, target_name, damage.damage));
+        }
+    }
+
+    if let Some(confusion) = ecs.read_storage::<Confusion>().get(item) {
+        add_effect(creator, EffectType::Confusion { turns: confusion.turns }, single.clone());
+        if is_player {
+            let item_name = item_display_name(ecs, item);
+            let target_name = name_of(ecs, target);
+            push_log(ecs, format!("You use {} on {}, confusing them.", item_name, target_name));
+        }
+    }
+
+    if ecs.read_storage::<Consumable>().get(item).is_some() {
+        ecs.entities().delete(item).expect("Delete failed");
+    }
+}
+
+fn item_display_name(ecs: &World, item: Entity) -> String {
+    let names = ecs.read_storage::<Name>();
+    let obfuscated_names = ecs.read_storage::<ObfuscatedName>();
+    let identification = ecs.fetch::<ItemIdentification>();
+    get_item_display_name(&names, &obfuscated_names, &identification, item)
+}
+
+fn name_of(ecs: &World, entity: Entity) -> String {
+    ecs.read_storage::<Name>().get(entity).map(|n| n.name.clone()).unwrap_or_else(|| "it".to_string())
+}
+
+fn push_log(ecs: &mut World, message: String) {
+    ecs.fetch_mut::<GameLog>().entries.push(message);
+}
@@ -0,0 +1,59 @@
+use specs::prelude::*;
+
+use crate::effects::{add_effect, EffectType, Targets};
+use crate::{HungerClock, HungerState};
+
+use super::GameLog;
+
+pub struct HungerSystem {}
+
+impl<'a> System<'a> for HungerSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, Entity>,
+        WriteStorage<'a, HungerClock>,
+        WriteExpect<'a, GameLog>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (entities, player_entity, mut hunger_clocks, mut gamelog) = data;
+
+        for (entity, clock) in (&entities, &mut hunger_clocks).join() {
+            clock.duration -= 1;
+            if clock.duration > 0 {
+                continue;
+            }
+
+            match clock.state {
+                HungerState::WellFed => {
+                    clock.state = HungerState::Normal;
+                    clock.duration = 200;
+                    if entity == *player_entity {
+                        gamelog.entries.push("You are no longer well fed.".to_string());
+                    }
+                }
+                HungerState::Normal => {
+                    clock.state = HungerState::Hungry;
+                    clock.duration = 200;
+                    if entity == *player_entity {
+                        gamelog.entries.push("You are hungry.".to_string());
+                    }
+                }
+                HungerState::Hungry => {
+                    clock.state = HungerState::Starving;
+                    clock.duration = 200;
+                    if entity == *player_entity {
+                        gamelog.entries.push("You are starving!".to_string());
+                    }
+                }
+                HungerState::Starving => {
+                    clock.duration = 10;
+                    if entity == *player_entity {
+                        gamelog.entries.push("Your hunger pangs are getting painful! You suffer 1 hp damage.".to_string());
+                    }
+                    add_effect(None, EffectType::Damage { amount: 1 }, Targets::Single { target: entity });
+                }
+            }
+        }
+    }
+}
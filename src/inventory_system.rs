@@ -1,8 +1,13 @@
 use specs::prelude::*;
 
-use crate::{AreaOfEffect, Confusion, Consumable, InflictsDamage, Map, ProvidesHealing, SufferDamage, WantsToDropItem, WantsToUseItem};
+use crate::effects::{add_effect, EffectType, Targets};
+use crate::item_identification::{get_item_display_name, queue_identification, ItemIdentification};
+use crate::{
+    AreaOfEffect, Consumable, Equippable, Equipped, HungerClock, HungerState, MagicMapper, Map, ObfuscatedName,
+    ProvidesFood, RunState, TownPortal, WantsToDropItem, WantsToUseItem,
+};
 
-use super::{CombatStats, GameLog, InBackpack, Name, Position, WantsToPickupItem};
+use super::{GameLog, InBackpack, Name, Position, WantsToPickupItem};
 
 pub struct ItemCollectionSystem {}
 
@@ -12,6 +17,8 @@ impl<'a> System<'a> for ItemCollectionSystem {
                        WriteStorage<'a, WantsToPickupItem>,
                        WriteStorage<'a, Position>,
                        ReadStorage<'a, Name>,
+                       ReadStorage<'a, ObfuscatedName>,
+                       ReadExpect<'a, ItemIdentification>,
                        WriteStorage<'a, InBackpack>,
     );
 
@@ -22,6 +29,8 @@ impl<'a> System<'a> for ItemCollectionSystem {
             mut wants_pickup,
             mut positions,
             names,
+            obfuscated_names,
+            identification,
             mut backpack
         ) = data;
 
@@ -30,7 +39,8 @@ impl<'a> System<'a> for ItemCollectionSystem {
             backpack.insert(pickup.item, InBackpack { owner: pickup.collected_by }).expect("Unable to insert backpack entity");
 
             if pickup.collected_by == *player_entity {
-                gamelog.entries.push(format!("You pick up the {}.", names.get(pickup.item).unwrap().name));
+                let item_name = get_item_display_name(&names, &obfuscated_names, &identification, pickup.item);
+                gamelog.entries.push(format!("You pick up the {}.", item_name));
             }
         }
 
@@ -47,14 +57,19 @@ impl<'a> System<'a> for ItemUseSystem {
         ReadExpect<'a, Map>,
         Entities<'a>,
         ReadStorage<'a, Name>,
-        ReadStorage<'a, Consumable>,
         ReadStorage<'a, WantsToUseItem>,
-        WriteStorage<'a, CombatStats>,
-        WriteStorage<'a, ProvidesHealing>,
-        WriteStorage<'a, InflictsDamage>,
-        WriteStorage<'a, SufferDamage>,
         ReadStorage<'a, AreaOfEffect>,
-        WriteStorage<'a, Confusion>
+        ReadStorage<'a, Equippable>,
+        WriteStorage<'a, Equipped>,
+        WriteStorage<'a, InBackpack>,
+        ReadStorage<'a, ObfuscatedName>,
+        ReadExpect<'a, ItemIdentification>,
+        ReadStorage<'a, Consumable>,
+        ReadStorage<'a, ProvidesFood>,
+        WriteStorage<'a, HungerClock>,
+        ReadStorage<'a, MagicMapper>,
+        ReadStorage<'a, TownPortal>,
+        WriteExpect<'a, RunState>,
     );
 
     fn run(&mut self, data: Self::SystemData) {
@@ -64,119 +79,133 @@ impl<'a> System<'a> for ItemUseSystem {
             map,
             entities,
             names,
-            consumables,
             wants_use,
-            mut combat_stats,
-            healing,
-            inflict_damage,
-            mut suffer_damage,
             aoe,
-            mut confused
+            equippable,
+            mut equipped,
+            mut backpack,
+            obfuscated_names,
+            identification,
+            consumables,
+            provides_food,
+            mut hunger_clocks,
+            magic_mapper,
+            town_portal,
+            mut run_state,
         ) = data;
 
         for (entity, useitem) in (&entities, &wants_use).join() {
-            let mut used_item = true;
-
-            // Targeting
-            let mut targets: Vec<Entity> = Vec::new();
-            match useitem.target {
-                None => { targets.push(*player_entity) }
-                Some(target) => {
-                    let area_affect = aoe.get(useitem.item);
-                    match area_affect {
-                        None => {
-                            // Simple target in tile
-                            let idx = map.xy_idx(target.x, target.y);
-                            for mob in map.tile_content[idx].iter() {
-                                targets.push(*mob);
-                            }
-                        }
-                        Some(area_affect) => {
-                            // AoE
-                            let mut blast_tiles = rltk::field_of_view(target, area_affect.radius, &*map);
-                            blast_tiles.retain(|p| p.x > 0 && p.x < map.width - 1 && p.y > 0 && p.y < map.height - 1);
-                            for tile_idx in blast_tiles.iter() {
-                                let idx = map.xy_idx(tile_idx.x, tile_idx.y);
-                                for mob in map.tile_content[idx].iter() {
-                                    targets.push(*mob);
-                                }
-                            }
-                        }
+            if entity == *player_entity && obfuscated_names.get(useitem.item).is_some() {
+                if let Some(true_name) = names.get(useitem.item) {
+                    if !identification.is_identified(&true_name.name) {
+                        queue_identification(true_name.name.clone());
                     }
                 }
             }
 
-            // Healing
-            let item_heals = healing.get(useitem.item);
-            match item_heals {
-                None => {}
-                Some(healer) => {
-                    for target in targets.iter() {
-                        let stats = combat_stats.get_mut(*target);
-                        if let Some(stats) = stats {
-                            stats.hp = i32::min(stats.max_hp, stats.hp + healer.heal_amount);
-                            if entity == *player_entity {
-                                gamelog.entries.push(format!("You use the {}, healing {} hp.", names.get(useitem.item).unwrap().name, healer.heal_amount));
-                            }
-                        }
-                    }
+            if magic_mapper.get(useitem.item).is_some() {
+                *run_state = RunState::MagicMapReveal { row: 0 };
+
+                if entity == *player_entity {
+                    let item_name = get_item_display_name(&names, &obfuscated_names, &identification, useitem.item);
+                    gamelog.entries.push(format!("You read the {} and the dungeon is revealed to you.", item_name));
                 }
+
+                if consumables.get(useitem.item).is_some() {
+                    entities.delete(useitem.item).expect("Delete failed");
+                }
+
+                continue;
             }
 
-            // If it inflicts damage, apply it to the target cell
-            let item_damages = inflict_damage.get(useitem.item);
-            match item_damages {
-                None => {}
-                Some(damage) => {
-                    used_item = false;
-                    for target in targets.iter() {
-                        SufferDamage::new_damage(&mut suffer_damage, *target, damage.damage);
-                        if entity == *player_entity {
-                            let target_name = names.get(*target).unwrap();
-                            let item_name = names.get(useitem.item).unwrap();
-                            gamelog.entries.push(format!("You use {} on {}, inflicting {} hp.", item_name.name, target_name.name, damage.damage));
-                        }
+            if town_portal.get(useitem.item).is_some() {
+                if entity == *player_entity {
+                    let item_name = get_item_display_name(&names, &obfuscated_names, &identification, useitem.item);
+                    gamelog.entries.push(format!("You read the {} and are pulled towards town.", item_name));
+                }
 
-                        used_item = true;
-                    }
+                add_effect(
+                    Some(entity),
+                    EffectType::TeleportTo {
+                        x: map.width / 2,
+                        y: map.height / 2,
+                        depth: 1,
+                        player_only: true,
+                        spawn_return: true,
+                    },
+                    Targets::Single { target: entity },
+                );
+
+                if consumables.get(useitem.item).is_some() {
+                    entities.delete(useitem.item).expect("Delete failed");
                 }
+
+                continue;
             }
 
-            // Confusion
-            let mut add_confusion = Vec::new();
-            {
-                let causes_confusion = confused.get(useitem.item);
-                match causes_confusion {
-                    None => {}
-                    Some(confusion) => {
-                        used_item = false;
-                        for target in targets.iter() {
-                            used_item = true;
-                            add_confusion.push((*target, confusion.turns));
-                            if entity == *player_entity {
-                                let mob_name = names.get(*target).unwrap();
-                                let item_name = names.get(useitem.item).unwrap();
-                                gamelog.entries.push(format!("You use {} on {}, confusing them.", item_name.name, mob_name.name));
-                            }
-                        }
-                    }
+            if provides_food.get(useitem.item).is_some() {
+                hunger_clocks.insert(entity, HungerClock { state: HungerState::WellFed, duration: 20 }).expect("Unable to insert hunger clock");
+
+                if entity == *player_entity {
+                    let item_name = get_item_display_name(&names, &obfuscated_names, &identification, useitem.item);
+                    gamelog.entries.push(format!("You eat the {}.", item_name));
+                }
+
+                if consumables.get(useitem.item).is_some() {
+                    entities.delete(useitem.item).expect("Delete failed");
                 }
-            }
 
-            for mob in add_confusion.iter() {
-                confused.insert(mob.0, Confusion { turns: mob.1 }).expect("Unable to insert status");
+                continue;
             }
 
-            // Delete if consumable
-            if used_item {
-                let consumable = consumables.get(useitem.item);
-                match consumable {
-                    None => {}
-                    Some(_) => {
-                        entities.delete(useitem.item).expect("Delete failed");
+            if let Some(can_equip) = equippable.get(useitem.item) {
+                let target_slot = can_equip.slot;
+
+                let mut already_equipped: Vec<Entity> = Vec::new();
+                for (equipped_entity, already_equipped_item) in (&entities, &equipped).join() {
+                    if already_equipped_item.owner == entity && already_equipped_item.slot == target_slot {
+                        already_equipped.push(equipped_entity);
                     }
                 }
+                for item in already_equipped.iter() {
+                    equipped.remove(*item);
+                    backpack.insert(*item, InBackpack { owner: entity }).expect("Unable to insert backpack entity");
+                }
+
+                equipped.insert(useitem.item, Equipped { owner: entity, slot: target_slot }).expect("Unable to insert equipped component");
+                backpack.remove(useitem.item);
+
+                if entity == *player_entity {
+                    let item_name = get_item_display_name(&names, &obfuscated_names, &identification, useitem.item);
+                    gamelog.entries.push(format!("You equip the {}.", item_name));
+                }
+
+                continue;
             }
+
+            let targets = match useitem.target {
+                None => Targets::Single { target: *player_entity },
+                Some(target) => {
+                    match aoe.get(useitem.item) {
+                        None => {
+                            let idx = map.xy_idx(target.x, target.y);
+                            Targets::TargetList { targets: map.tile_content[idx].clone() }
+                        }
+                        Some(area_affect) => {
+                            let mut blast_tiles = rltk::field_of_view(target, area_affect.radius, &*map);
+                            blast_tiles.retain(|p| p.x > 0 && p.x < map.width - 1 && p.y > 0 && p.y < map.height - 1);
+                            let mut targets = Vec::new();
+                            for tile_idx in blast_tiles.iter() {
+                                let idx = map.xy_idx(tile_idx.x, tile_idx.y);
+                                targets.extend(map.tile_content[idx].iter());
+                            }
+                            Targets::TargetList { targets }
+                        }
+                    }
+                }
+            };
+
+            add_effect(Some(entity), EffectType::ItemUse { item: useitem.item }, targets);
         }
     }
 }
@@ -190,6 +219,8 @@ impl<'a> System<'a> for ItemDropSystem {
         Entities<'a>,
         WriteStorage<'a, WantsToDropItem>,
         ReadStorage<'a, Name>,
+        ReadStorage<'a, ObfuscatedName>,
+        ReadExpect<'a, ItemIdentification>,
         WriteStorage<'a, Position>,
         WriteStorage<'a, InBackpack>,
     );
@@ -201,6 +232,8 @@ impl<'a> System<'a> for ItemDropSystem {
             entities,
             mut wants_drop,
             names,
+            obfuscated_names,
+            identification,
             mut positions,
             mut backpack
         ) = data;
@@ -216,7 +249,8 @@ impl<'a> System<'a> for ItemDropSystem {
             backpack.remove(to_drop.item);
 
             if entity == *player_entity {
-                gamelog.entries.push(format!("You drop the {}.", names.get(to_drop.item).unwrap().name));
+                let item_name = get_item_display_name(&names, &obfuscated_names, &identification, to_drop.item);
+                gamelog.entries.push(format!("You drop the {}.", item_name));
             }
         }
 
@@ -0,0 +1,101 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Mutex;
+
+use lazy_static::lazy_static;
+use rltk::RandomNumberGenerator;
+use specs::prelude::*;
+
+use super::{Name, ObfuscatedName};
+
+const POTION_ADJECTIVES: &[&str] = &["murky", "swirling", "bubbling", "fizzy", "clear", "viscous", "smoky", "oily"];
+const SCROLL_GLYPHS: &[&str] = &["ZELGO", "MER", "XXXX", "ELBIB", "NNEY", "QUAZ", "YAVE", "KRIK"];
+
+/// Per-run record of which item types have been identified. Starts empty; it holds
+/// no mapping from item type to masked name (each item carries its own `ObfuscatedName`).
+#[derive(Default)]
+pub struct ItemIdentification {
+    identified_items: HashSet<String>,
+}
+
+impl ItemIdentification {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_identified(&self, item_type: &str) -> bool {
+        self.identified_items.contains(item_type)
+    }
+
+    pub fn identify(&mut self, item_type: &str) {
+        self.identified_items.insert(item_type.to_string());
+    }
+}
+
+/// Picks a random masked display name for an unidentified item, based on its
+/// real name (e.g. "Potion of Healing" -> "murky potion"). Intended to be called
+/// once, when the item is spawned, to produce its `ObfuscatedName` component.
+/// Each call rolls independently, so two items of the same type can get different
+/// masked names.
+pub fn roll_obfuscated_name(rng: &mut RandomNumberGenerator, true_name: &str) -> String {
+    let lower = true_name.to_lowercase();
+    if lower.contains("potion") {
+        let adjective = POTION_ADJECTIVES[rng.roll_dice(1, POTION_ADJECTIVES.len() as i32) as usize - 1];
+        format!("{} potion", adjective)
+    } else if lower.contains("scroll") {
+        let glyph = SCROLL_GLYPHS[rng.roll_dice(1, SCROLL_GLYPHS.len() as i32) as usize - 1];
+        format!("scroll labeled {}", glyph)
+    } else {
+        "unidentified item".to_string()
+    }
+}
+
+/// Marker enqueued when a player uses an item of a type that isn't identified yet.
+pub struct IdentifiedItem {
+    pub name: String,
+}
+
+lazy_static! {
+    static ref IDENTIFY_QUEUE: Mutex<VecDeque<IdentifiedItem>> = Mutex::new(VecDeque::new());
+}
+
+pub fn queue_identification(name: String) {
+    IDENTIFY_QUEUE.lock().unwrap().push_back(IdentifiedItem { name });
+}
+
+/// Drains pending `IdentifiedItem` markers, adding each item type to the
+/// identified set so it displays under its real name everywhere from now on.
+pub fn run_identification_queue(ecs: &mut World) {
+    loop {
+        let next = IDENTIFY_QUEUE.lock().unwrap().pop_front();
+        match next {
+            None => break,
+            Some(identified) => {
+                ecs.fetch_mut::<ItemIdentification>().identify(&identified.name);
+            }
+        }
+    }
+}
+
+/// Returns the name a player should currently see for `item` — its real name if
+/// the type has been identified, otherwise the item's own `ObfuscatedName` (or the
+/// real name if it has none).
+pub fn get_item_display_name(
+    names: &ReadStorage<Name>,
+    obfuscated_names: &ReadStorage<ObfuscatedName>,
+    identification: &ItemIdentification,
+    item: Entity,
+) -> String {
+    let true_name = match names.get(item) {
+        Some(name) => &name.name,
+        None => return "unknown item".to_string(),
+    };
+
+    if identification.is_identified(true_name) {
+        return true_name.clone();
+    }
+
+    match obfuscated_names.get(item) {
+        Some(obfuscated) => obfuscated.name.clone(),
+        None => true_name.clone(),
+    }
+}
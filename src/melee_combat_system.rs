@@ -0,0 +1,57 @@
+use specs::prelude::*;
+
+use crate::effects::{add_effect, EffectType, Targets};
+use crate::{
+    equipped_defense_bonus, equipped_melee_bonus, hunger_combat_bonus, CombatStats, DefenseBonus, Equipped, HungerClock,
+    MeleePowerBonus, Name, WantsToMelee,
+};
+
+use super::GameLog;
+
+pub struct MeleeCombatSystem {}
+
+impl<'a> System<'a> for MeleeCombatSystem {
+    type SystemData = (
+        Entities<'a>,
+        WriteExpect<'a, GameLog>,
+        WriteStorage<'a, WantsToMelee>,
+        ReadStorage<'a, Name>,
+        ReadStorage<'a, CombatStats>,
+        ReadStorage<'a, Equipped>,
+        ReadStorage<'a, MeleePowerBonus>,
+        ReadStorage<'a, DefenseBonus>,
+        ReadStorage<'a, HungerClock>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (entities, mut gamelog, mut wants_melee, names, combat_stats, equipped, power_bonuses, defense_bonuses, hunger_clocks) = data;
+
+        for (attacker, wants_melee, name, stats) in (&entities, &wants_melee, &names, &combat_stats).join() {
+            if stats.hp <= 0 {
+                continue;
+            }
+
+            let target = wants_melee.target;
+            if let (Some(target_stats), Some(target_name)) = (combat_stats.get(target), names.get(target)) {
+                if target_stats.hp <= 0 {
+                    continue;
+                }
+
+                let power = stats.power
+                    + equipped_melee_bonus(&equipped, &power_bonuses, attacker)
+                    + hunger_combat_bonus(&hunger_clocks, attacker);
+                let defense = target_stats.defense + equipped_defense_bonus(&equipped, &defense_bonuses, target);
+
+                let damage = i32::max(0, power - defense);
+                if damage == 0 {
+                    gamelog.entries.push(format!("{} is unable to hurt {}.", name.name, target_name.name));
+                } else {
+                    gamelog.entries.push(format!("{} hits {}, for {} hp.", name.name, target_name.name, damage));
+                    add_effect(Some(attacker), EffectType::Damage { amount: damage }, Targets::Single { target });
+                }
+            }
+        }
+
+        wants_melee.clear();
+    }
+}
@@ -0,0 +1,85 @@
+use rltk::{FontCharType, Rltk, RGB};
+use specs::prelude::*;
+use specs_derive::Component;
+
+use super::Position;
+
+#[derive(Component)]
+pub struct ParticleLifetime {
+    pub lifetime_ms: f32,
+    pub fg: RGB,
+    pub bg: RGB,
+    pub glyph: FontCharType,
+}
+
+struct ParticleRequest {
+    x: i32,
+    y: i32,
+    fg: RGB,
+    bg: RGB,
+    glyph: FontCharType,
+    lifetime_ms: f32,
+}
+
+pub struct ParticleBuilder {
+    requests: Vec<ParticleRequest>,
+}
+
+impl ParticleBuilder {
+    pub fn new() -> ParticleBuilder {
+        ParticleBuilder { requests: Vec::new() }
+    }
+
+    pub fn request(&mut self, x: i32, y: i32, fg: RGB, bg: RGB, glyph: FontCharType, lifetime_ms: f32) {
+        self.requests.push(ParticleRequest { x, y, fg, bg, glyph, lifetime_ms });
+    }
+}
+
+/// Drains the `ParticleBuilder` queue into short-lived particle entities each tick.
+pub struct ParticleSpawnSystem {}
+
+impl<'a> System<'a> for ParticleSpawnSystem {
+    type SystemData = (
+        Entities<'a>,
+        WriteStorage<'a, Position>,
+        WriteStorage<'a, ParticleLifetime>,
+        WriteExpect<'a, ParticleBuilder>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (entities, mut positions, mut particles, mut particle_builder) = data;
+
+        for new_particle in particle_builder.requests.iter() {
+            let p = entities.create();
+            positions.insert(p, Position { x: new_particle.x, y: new_particle.y }).expect("Unable to insert position");
+            particles
+                .insert(p, ParticleLifetime {
+                    lifetime_ms: new_particle.lifetime_ms,
+                    fg: new_particle.fg,
+                    bg: new_particle.bg,
+                    glyph: new_particle.glyph,
+                })
+                .expect("Unable to insert lifetime");
+        }
+
+        particle_builder.requests.clear();
+    }
+}
+
+/// Ages out and deletes particles whose lifetime has elapsed; call once per frame.
+pub fn cull_dead_particles(ecs: &mut World, ctx: &Rltk) {
+    let mut dead_particles: Vec<Entity> = Vec::new();
+    {
+        let mut particles = ecs.write_storage::<ParticleLifetime>();
+        let entities = ecs.entities();
+        for (entity, particle) in (&entities, &mut particles).join() {
+            particle.lifetime_ms -= ctx.frame_time_ms;
+            if particle.lifetime_ms < 0.0 {
+                dead_particles.push(entity);
+            }
+        }
+    }
+    for dead in dead_particles.iter() {
+        ecs.delete_entity(*dead).expect("Particle will not die");
+    }
+}
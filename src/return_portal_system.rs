@@ -0,0 +1,67 @@
+use specs::prelude::*;
+
+use crate::effects::{add_effect, EffectType, Targets};
+use crate::{Position, ReturnPortal, ReturnPortalTile};
+
+use super::GameLog;
+
+/// Sends the player back to their `ReturnPortal` destination when they share a
+/// tile with a `ReturnPortalTile` entity, then consumes both.
+pub struct ReturnPortalSystem {}
+
+impl<'a> System<'a> for ReturnPortalSystem {
+    type SystemData = (
+        Entities<'a>,
+        ReadExpect<'a, Entity>,
+        ReadStorage<'a, Position>,
+        ReadStorage<'a, ReturnPortalTile>,
+        WriteStorage<'a, ReturnPortal>,
+        WriteExpect<'a, GameLog>,
+    );
+
+    fn run(&mut self, data: Self::SystemData) {
+        let (entities, player_entity, positions, portal_tiles, mut return_portals, mut gamelog) = data;
+
+        let player_pos = match positions.get(*player_entity) {
+            Some(pos) => (pos.x, pos.y),
+            None => return,
+        };
+
+        let return_portal = match return_portals.get(*player_entity) {
+            Some(return_portal) => return_portal.clone(),
+            None => return,
+        };
+
+        let standing_on_portal = (&entities, &portal_tiles, &positions)
+            .join()
+            .any(|(_, _, pos)| (pos.x, pos.y) == player_pos);
+
+        if !standing_on_portal {
+            return;
+        }
+
+        return_portals.remove(*player_entity);
+        let portals_to_remove: Vec<Entity> = (&entities, &portal_tiles, &positions)
+            .join()
+            .filter(|(_, _, pos)| (pos.x, pos.y) == player_pos)
+            .map(|(entity, _, _)| entity)
+            .collect();
+        for portal in portals_to_remove.iter() {
+            entities.delete(*portal).expect("Unable to delete return portal tile");
+        }
+
+        gamelog.entries.push("You step through the portal and return to the dungeon.".to_string());
+
+        add_effect(
+            Some(*player_entity),
+            EffectType::TeleportTo {
+                x: return_portal.x,
+                y: return_portal.y,
+                depth: return_portal.depth,
+                player_only: true,
+                spawn_return: false,
+            },
+            Targets::Single { target: *player_entity },
+        );
+    }
+}